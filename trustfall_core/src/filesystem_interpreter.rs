@@ -1,11 +1,18 @@
 #![allow(dead_code)]
 
-use std::fs::{self, ReadDir};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, Metadata, ReadDir};
+use std::io::{BufRead, BufReader, Read};
 use std::iter;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::Arc;
 
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::{Deserialize, Serialize};
 
 use crate::interpreter::AsVertex;
@@ -17,14 +24,76 @@ use crate::{
     ir::{EdgeParameters, FieldValue},
 };
 
+/// Lazily-built index from content hash to every file under `origin` that
+/// hashes to it. Computed once per adapter instance, the first time an
+/// `out_File_SameContents` edge is resolved; the `content_hash` property
+/// hashes only the one file and does not build it.
+type ContentHashIndex = Rc<RefCell<Option<HashMap<String, Vec<FileVertex>>>>>;
+
+const DEFAULT_IGNORE_PATTERNS: &[&str] = &[".git", ".vscode", "target"];
+
+/// The directory tree's root path plus the compiled gitignore-style matchers
+/// used to decide which entries the directory iterators should skip. Cheap
+/// to clone: both fields are reference-counted.
+#[derive(Clone)]
+struct FilesystemRoot {
+    origin: Rc<String>,
+    ignore_matcher: Rc<Gitignore>,
+}
+
+impl std::fmt::Debug for FilesystemRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilesystemRoot").field("origin", &self.origin).finish()
+    }
+}
+
+impl FilesystemRoot {
+    fn new(origin: String, patterns: &[String]) -> FilesystemRoot {
+        let mut builder = GitignoreBuilder::new(&origin);
+        for pattern in patterns {
+            builder.add_line(None, pattern).expect("invalid ignore pattern");
+        }
+        let ignore_matcher =
+            Rc::new(builder.build().expect("failed to compile ignore patterns"));
+        FilesystemRoot { origin: Rc::new(origin), ignore_matcher }
+    }
+
+    fn is_ignored(&self, relative_path: &str, is_dir: bool) -> bool {
+        let mut full_path = PathBuf::new();
+        full_path.extend([self.origin.as_str(), relative_path]);
+        self.ignore_matcher.matched(&full_path, is_dir).is_ignore()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FilesystemInterpreter {
-    origin: Rc<String>,
+    root: FilesystemRoot,
+    content_hash_index: ContentHashIndex,
 }
 
 impl FilesystemInterpreter {
+    /// Compiles the default `.git`/`.vscode`/`target` skip list through the
+    /// same `ignore`-backed matcher as [`Self::with_ignore_patterns`]. This
+    /// pulls in the full gitignore glob engine just to match three literal
+    /// names, but sharing one matcher implementation keeps default and
+    /// custom behavior identical instead of subtly diverging.
     pub fn new(origin: String) -> FilesystemInterpreter {
-        FilesystemInterpreter { origin: Rc::new(origin) }
+        let patterns = DEFAULT_IGNORE_PATTERNS.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        Self::with_ignore_patterns(origin, patterns)
+    }
+
+    /// Builds an interpreter that skips entries matching the given
+    /// gitignore-style patterns instead of the default `.git`/`.vscode`/
+    /// `target` list. Supports the core gitignore glob semantics: `*`/`?`
+    /// wildcards, `**` for any number of path segments, a leading `/` to
+    /// anchor a pattern to `origin`, a trailing `/` to match directories
+    /// only, and a leading `!` to re-include a path an earlier pattern
+    /// excluded (the last matching pattern wins).
+    pub fn with_ignore_patterns(origin: String, patterns: Vec<String>) -> FilesystemInterpreter {
+        FilesystemInterpreter {
+            root: FilesystemRoot::new(origin, &patterns),
+            content_hash_index: Rc::new(RefCell::new(None)),
+        }
     }
 }
 
@@ -55,17 +124,17 @@ impl Iterator for OriginIterator {
 
 #[derive(Debug)]
 struct DirectoryContainsFileIterator {
-    origin: Rc<String>,
+    root: FilesystemRoot,
     directory: DirectoryVertex,
     file_iter: ReadDir,
 }
 
 impl DirectoryContainsFileIterator {
-    pub fn new(origin: Rc<String>, directory: &DirectoryVertex) -> DirectoryContainsFileIterator {
+    pub fn new(root: FilesystemRoot, directory: &DirectoryVertex) -> DirectoryContainsFileIterator {
         let mut buf = PathBuf::new();
-        buf.extend([&*origin, &directory.path]);
+        buf.extend([root.origin.as_str(), &directory.path]);
         DirectoryContainsFileIterator {
-            origin,
+            root,
             directory: directory.clone(),
             file_iter: fs::read_dir(buf).unwrap(),
         }
@@ -80,22 +149,26 @@ impl Iterator for DirectoryContainsFileIterator {
             if let Some(outcome) = self.file_iter.next() {
                 match outcome {
                     Ok(dir_entry) => {
-                        let metadata = match dir_entry.metadata() {
+                        let file_type = match dir_entry.file_type() {
                             Ok(res) => res,
                             _ => continue,
                         };
-                        if metadata.is_file() {
-                            let name = dir_entry.file_name().to_str().unwrap().to_owned();
-                            let mut buf = PathBuf::new();
-                            buf.extend([&self.directory.path, &name]);
+                        let metadata =
+                            dir_entry.metadata().ok().map(|m| FilesystemMetadata::from_metadata(&m));
+                        let name = dir_entry.file_name().to_str().unwrap().to_owned();
+                        let mut buf = PathBuf::new();
+                        buf.extend([&self.directory.path, &name]);
+                        let relative_path = buf.to_str().unwrap().to_owned();
+                        if self.root.is_ignored(&relative_path, file_type.is_dir()) {
+                            continue;
+                        }
+
+                        if file_type.is_file() {
                             let extension = Path::new(&name)
                                 .extension()
                                 .map(|x| x.to_str().unwrap().to_owned());
-                            let result = FileVertex {
-                                name,
-                                extension,
-                                path: buf.to_str().unwrap().to_owned(),
-                            };
+                            let result =
+                                FileVertex { name, extension, path: relative_path, metadata };
                             return Some(FilesystemVertex::File(result));
                         }
                     }
@@ -110,16 +183,16 @@ impl Iterator for DirectoryContainsFileIterator {
 
 #[derive(Debug)]
 struct SubdirectoryIterator {
-    origin: Rc<String>,
+    root: FilesystemRoot,
     directory: DirectoryVertex,
     dir_iter: ReadDir,
 }
 
 impl SubdirectoryIterator {
-    pub fn new(origin: Rc<String>, directory: &DirectoryVertex) -> Self {
+    pub fn new(root: FilesystemRoot, directory: &DirectoryVertex) -> Self {
         let mut buf = PathBuf::new();
-        buf.extend([&*origin, &directory.path]);
-        Self { origin, directory: directory.clone(), dir_iter: fs::read_dir(buf).unwrap() }
+        buf.extend([root.origin.as_str(), &directory.path]);
+        Self { root, directory: directory.clone(), dir_iter: fs::read_dir(buf).unwrap() }
     }
 }
 
@@ -131,20 +204,22 @@ impl Iterator for SubdirectoryIterator {
             if let Some(outcome) = self.dir_iter.next() {
                 match outcome {
                     Ok(dir_entry) => {
-                        let metadata = match dir_entry.metadata() {
+                        let file_type = match dir_entry.file_type() {
                             Ok(res) => res,
                             _ => continue,
                         };
-                        if metadata.is_dir() {
-                            let name = dir_entry.file_name().to_str().unwrap().to_owned();
-                            if name == ".git" || name == ".vscode" || name == "target" {
-                                continue;
-                            }
+                        let metadata =
+                            dir_entry.metadata().ok().map(|m| FilesystemMetadata::from_metadata(&m));
+                        let name = dir_entry.file_name().to_str().unwrap().to_owned();
+                        let mut buf = PathBuf::new();
+                        buf.extend([&self.directory.path, &name]);
+                        let relative_path = buf.to_str().unwrap().to_owned();
+                        if self.root.is_ignored(&relative_path, file_type.is_dir()) {
+                            continue;
+                        }
 
-                            let mut buf = PathBuf::new();
-                            buf.extend([&self.directory.path, &name]);
-                            let result =
-                                DirectoryVertex { name, path: buf.to_str().unwrap().to_owned() };
+                        if file_type.is_dir() {
+                            let result = DirectoryVertex { name, path: relative_path, metadata };
                             return Some(FilesystemVertex::Directory(result));
                         }
                     }
@@ -157,25 +232,283 @@ impl Iterator for SubdirectoryIterator {
     }
 }
 
+#[derive(Debug)]
+struct DirectoryContainsSymlinkIterator {
+    root: FilesystemRoot,
+    directory: DirectoryVertex,
+    entry_iter: ReadDir,
+}
+
+impl DirectoryContainsSymlinkIterator {
+    pub fn new(root: FilesystemRoot, directory: &DirectoryVertex) -> Self {
+        let mut buf = PathBuf::new();
+        buf.extend([root.origin.as_str(), &directory.path]);
+        Self { directory: directory.clone(), entry_iter: fs::read_dir(buf).unwrap(), root }
+    }
+}
+
+impl Iterator for DirectoryContainsSymlinkIterator {
+    type Item = FilesystemVertex;
+
+    fn next(&mut self) -> Option<FilesystemVertex> {
+        loop {
+            let outcome = self.entry_iter.next()?;
+            let Ok(dir_entry) = outcome else {
+                continue;
+            };
+            let Ok(file_type) = dir_entry.file_type() else {
+                continue;
+            };
+            if !file_type.is_symlink() {
+                continue;
+            }
+            let name = dir_entry.file_name().to_str().unwrap().to_owned();
+            let mut buf = PathBuf::new();
+            buf.extend([&self.directory.path, &name]);
+            let relative_path = buf.to_str().unwrap().to_owned();
+            if self.root.is_ignored(&relative_path, false) {
+                continue;
+            }
+            let target = read_link_target(&dir_entry.path());
+            let result = SymlinkVertex { name, path: relative_path, target };
+            return Some(FilesystemVertex::Symlink(result));
+        }
+    }
+}
+
+/// A directory whose entries are still being streamed, paired with its
+/// depth relative to the directory the descendant traversal started from.
+#[derive(Debug)]
+struct DescendantFrame {
+    read_dir: ReadDir,
+    relative_path: String,
+    depth: u64,
+}
+
+/// Reads the raw target text of a symlink, empty string if it can't be read.
+fn read_link_target(link_path: &Path) -> String {
+    fs::read_link(link_path)
+        .ok()
+        .and_then(|target| target.to_str().map(|x| x.to_owned()))
+        .unwrap_or_default()
+}
+
+#[derive(Debug)]
+struct AllDescendantFilesIterator {
+    root: FilesystemRoot,
+    max_depth: Option<u64>,
+    worklist: VecDeque<DescendantFrame>,
+}
+
+impl AllDescendantFilesIterator {
+    pub fn new(
+        root: FilesystemRoot,
+        directory: &DirectoryVertex,
+        max_depth: Option<u64>,
+    ) -> AllDescendantFilesIterator {
+        let mut buf = PathBuf::new();
+        buf.extend([root.origin.as_str(), &directory.path]);
+        let mut worklist = VecDeque::new();
+        if let Ok(read_dir) = fs::read_dir(buf) {
+            worklist.push_back(DescendantFrame {
+                read_dir,
+                relative_path: directory.path.clone(),
+                depth: 0,
+            });
+        }
+        AllDescendantFilesIterator { root, max_depth, worklist }
+    }
+}
+
+impl Iterator for AllDescendantFilesIterator {
+    type Item = FilesystemVertex;
+
+    fn next(&mut self) -> Option<FilesystemVertex> {
+        loop {
+            let Some(frame) = self.worklist.front_mut() else {
+                return None;
+            };
+            match frame.read_dir.next() {
+                Some(Ok(dir_entry)) => {
+                    let file_type = match dir_entry.file_type() {
+                        Ok(file_type) => file_type,
+                        Err(_) => continue,
+                    };
+                    let metadata =
+                        dir_entry.metadata().ok().map(|m| FilesystemMetadata::from_metadata(&m));
+                    let name = dir_entry.file_name().to_str().unwrap().to_owned();
+                    let mut buf = PathBuf::new();
+                    buf.extend([&frame.relative_path, &name]);
+                    let relative_path = buf.to_str().unwrap().to_owned();
+                    if self.root.is_ignored(&relative_path, file_type.is_dir()) {
+                        continue;
+                    }
+
+                    if file_type.is_dir() {
+                        let depth = frame.depth;
+                        if should_descend(depth, self.max_depth) {
+                            let mut full = PathBuf::new();
+                            full.extend([self.root.origin.as_str(), &relative_path]);
+                            if let Ok(read_dir) = fs::read_dir(full) {
+                                self.worklist.push_back(DescendantFrame {
+                                    read_dir,
+                                    relative_path,
+                                    depth: depth + 1,
+                                });
+                            }
+                        }
+                    } else if file_type.is_file() {
+                        let extension = Path::new(&name)
+                            .extension()
+                            .map(|x| x.to_str().unwrap().to_owned());
+                        let result =
+                            FileVertex { name, extension, path: relative_path, metadata };
+                        return Some(FilesystemVertex::File(result));
+                    }
+                }
+                Some(Err(_)) => continue,
+                None => {
+                    self.worklist.pop_front();
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AllDescendantDirectoriesIterator {
+    root: FilesystemRoot,
+    max_depth: Option<u64>,
+    worklist: VecDeque<DescendantFrame>,
+}
+
+impl AllDescendantDirectoriesIterator {
+    pub fn new(
+        root: FilesystemRoot,
+        directory: &DirectoryVertex,
+        max_depth: Option<u64>,
+    ) -> AllDescendantDirectoriesIterator {
+        let mut buf = PathBuf::new();
+        buf.extend([root.origin.as_str(), &directory.path]);
+        let mut worklist = VecDeque::new();
+        if let Ok(read_dir) = fs::read_dir(buf) {
+            worklist.push_back(DescendantFrame {
+                read_dir,
+                relative_path: directory.path.clone(),
+                depth: 0,
+            });
+        }
+        AllDescendantDirectoriesIterator { root, max_depth, worklist }
+    }
+}
+
+impl Iterator for AllDescendantDirectoriesIterator {
+    type Item = FilesystemVertex;
+
+    fn next(&mut self) -> Option<FilesystemVertex> {
+        loop {
+            let Some(frame) = self.worklist.front_mut() else {
+                return None;
+            };
+            match frame.read_dir.next() {
+                Some(Ok(dir_entry)) => {
+                    let file_type = match dir_entry.file_type() {
+                        Ok(file_type) => file_type,
+                        Err(_) => continue,
+                    };
+                    if !file_type.is_dir() {
+                        continue;
+                    }
+                    let metadata =
+                        dir_entry.metadata().ok().map(|m| FilesystemMetadata::from_metadata(&m));
+                    let name = dir_entry.file_name().to_str().unwrap().to_owned();
+                    let mut buf = PathBuf::new();
+                    buf.extend([&frame.relative_path, &name]);
+                    let relative_path = buf.to_str().unwrap().to_owned();
+                    if self.root.is_ignored(&relative_path, true) {
+                        continue;
+                    }
+                    let depth = frame.depth;
+
+                    if should_descend(depth, self.max_depth) {
+                        let mut full = PathBuf::new();
+                        full.extend([self.root.origin.as_str(), &relative_path]);
+                        if let Ok(read_dir) = fs::read_dir(full) {
+                            self.worklist.push_back(DescendantFrame {
+                                read_dir,
+                                relative_path: relative_path.clone(),
+                                depth: depth + 1,
+                            });
+                        }
+                    }
+
+                    let result = DirectoryVertex { name, path: relative_path, metadata };
+                    return Some(FilesystemVertex::Directory(result));
+                }
+                Some(Err(_)) => continue,
+                None => {
+                    self.worklist.pop_front();
+                }
+            }
+        }
+    }
+}
+
+/// Lazily reads a file's lines through a `BufReader`, yielding one `Line`
+/// vertex per line with a 1-based line number and the trailing newline
+/// stripped. Files that can't be opened yield no lines.
+#[derive(Debug)]
+struct ContainsLineIterator {
+    file_path: String,
+    lines: Option<iter::Enumerate<std::io::Lines<BufReader<fs::File>>>>,
+}
+
+impl ContainsLineIterator {
+    pub fn new(root: &FilesystemRoot, file: &FileVertex) -> ContainsLineIterator {
+        let mut buf = PathBuf::new();
+        buf.extend([root.origin.as_str(), &file.path]);
+        let lines =
+            fs::File::open(buf).ok().map(|handle| BufReader::new(handle).lines().enumerate());
+        ContainsLineIterator { file_path: file.path.clone(), lines }
+    }
+}
+
+impl Iterator for ContainsLineIterator {
+    type Item = FilesystemVertex;
+
+    fn next(&mut self) -> Option<FilesystemVertex> {
+        loop {
+            let (index, outcome) = self.lines.as_mut()?.next()?;
+            if let Ok(text) = outcome {
+                return Some(FilesystemVertex::Line(LineVertex {
+                    number: index as u64 + 1,
+                    text,
+                    file_path: self.file_path.clone(),
+                }));
+            }
+        }
+    }
+}
+
 pub type ContextAndValue = (DataContext<FilesystemVertex>, FieldValue);
 
 type IndividualEdgeResolver<'a> =
-    fn(Rc<String>, &FilesystemVertex) -> VertexIterator<'a, FilesystemVertex>;
+    fn(FilesystemRoot, &FilesystemVertex) -> VertexIterator<'a, FilesystemVertex>;
 type ContextAndIterableOfEdges<'a, V> = (DataContext<V>, VertexIterator<'a, FilesystemVertex>);
 
 struct EdgeResolverIterator<'a, V: AsVertex<FilesystemVertex>> {
-    origin: Rc<String>,
+    root: FilesystemRoot,
     contexts: VertexIterator<'a, DataContext<V>>,
     edge_resolver: IndividualEdgeResolver<'a>,
 }
 
 impl<'a, V: AsVertex<FilesystemVertex>> EdgeResolverIterator<'a, V> {
     pub fn new(
-        origin: Rc<String>,
+        root: FilesystemRoot,
         contexts: VertexIterator<'a, DataContext<V>>,
         edge_resolver: IndividualEdgeResolver<'a>,
     ) -> Self {
-        Self { origin, contexts, edge_resolver }
+        Self { root, contexts, edge_resolver }
     }
 }
 
@@ -185,7 +518,93 @@ impl<'a, V: AsVertex<FilesystemVertex>> Iterator for EdgeResolverIterator<'a, V>
     fn next(&mut self) -> Option<ContextAndIterableOfEdges<'a, V>> {
         if let Some(context) = self.contexts.next() {
             if let Some(vertex) = context.active_vertex::<FilesystemVertex>() {
-                let neighbors = (self.edge_resolver)(self.origin.clone(), vertex);
+                let neighbors = (self.edge_resolver)(self.root.clone(), vertex);
+                Some((context, neighbors))
+            } else {
+                let empty_iterator: iter::Empty<FilesystemVertex> = iter::empty();
+                Some((context, Box::new(empty_iterator)))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+type IndividualParameterizedEdgeResolver<'a> =
+    fn(FilesystemRoot, &FilesystemVertex, Option<u64>) -> VertexIterator<'a, FilesystemVertex>;
+
+/// Like [`EdgeResolverIterator`], but threads an optional `max_depth`
+/// (parsed once from the edge's `EdgeParameters`) through to every vertex's
+/// edge resolution, for edges like `out_Directory_AllDescendantFiles` that
+/// accept a depth bound.
+struct ParameterizedEdgeResolverIterator<'a, V: AsVertex<FilesystemVertex>> {
+    root: FilesystemRoot,
+    contexts: VertexIterator<'a, DataContext<V>>,
+    edge_resolver: IndividualParameterizedEdgeResolver<'a>,
+    max_depth: Option<u64>,
+}
+
+impl<'a, V: AsVertex<FilesystemVertex>> ParameterizedEdgeResolverIterator<'a, V> {
+    pub fn new(
+        root: FilesystemRoot,
+        contexts: VertexIterator<'a, DataContext<V>>,
+        edge_resolver: IndividualParameterizedEdgeResolver<'a>,
+        max_depth: Option<u64>,
+    ) -> Self {
+        Self { root, contexts, edge_resolver, max_depth }
+    }
+}
+
+impl<'a, V: AsVertex<FilesystemVertex>> Iterator for ParameterizedEdgeResolverIterator<'a, V> {
+    type Item = (DataContext<V>, VertexIterator<'a, FilesystemVertex>);
+
+    fn next(&mut self) -> Option<ContextAndIterableOfEdges<'a, V>> {
+        if let Some(context) = self.contexts.next() {
+            if let Some(vertex) = context.active_vertex::<FilesystemVertex>() {
+                let neighbors = (self.edge_resolver)(self.root.clone(), vertex, self.max_depth);
+                Some((context, neighbors))
+            } else {
+                let empty_iterator: iter::Empty<FilesystemVertex> = iter::empty();
+                Some((context, Box::new(empty_iterator)))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+type IndividualCachedEdgeResolver<'a> =
+    fn(FilesystemRoot, ContentHashIndex, &FilesystemVertex) -> VertexIterator<'a, FilesystemVertex>;
+
+/// Like [`EdgeResolverIterator`], but also threads the adapter's shared,
+/// lazily-populated cache (e.g. the content-hash index) through to every
+/// vertex's edge resolution.
+struct CachedEdgeResolverIterator<'a, V: AsVertex<FilesystemVertex>> {
+    root: FilesystemRoot,
+    index: ContentHashIndex,
+    contexts: VertexIterator<'a, DataContext<V>>,
+    edge_resolver: IndividualCachedEdgeResolver<'a>,
+}
+
+impl<'a, V: AsVertex<FilesystemVertex>> CachedEdgeResolverIterator<'a, V> {
+    pub fn new(
+        root: FilesystemRoot,
+        index: ContentHashIndex,
+        contexts: VertexIterator<'a, DataContext<V>>,
+        edge_resolver: IndividualCachedEdgeResolver<'a>,
+    ) -> Self {
+        Self { root, index, contexts, edge_resolver }
+    }
+}
+
+impl<'a, V: AsVertex<FilesystemVertex>> Iterator for CachedEdgeResolverIterator<'a, V> {
+    type Item = (DataContext<V>, VertexIterator<'a, FilesystemVertex>);
+
+    fn next(&mut self) -> Option<ContextAndIterableOfEdges<'a, V>> {
+        if let Some(context) = self.contexts.next() {
+            if let Some(vertex) = context.active_vertex::<FilesystemVertex>() {
+                let neighbors =
+                    (self.edge_resolver)(self.root.clone(), self.index.clone(), vertex);
                 Some((context, neighbors))
             } else {
                 let empty_iterator: iter::Empty<FilesystemVertex> = iter::empty();
@@ -201,12 +620,15 @@ impl<'a, V: AsVertex<FilesystemVertex>> Iterator for EdgeResolverIterator<'a, V>
 pub enum FilesystemVertex {
     Directory(DirectoryVertex),
     File(FileVertex),
+    Symlink(SymlinkVertex),
+    Line(LineVertex),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DirectoryVertex {
     pub name: String,
     pub path: String,
+    pub metadata: Option<FilesystemMetadata>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -214,28 +636,446 @@ pub struct FileVertex {
     pub name: String,
     pub extension: Option<String>,
     pub path: String,
+    pub metadata: Option<FilesystemMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymlinkVertex {
+    pub name: String,
+    pub path: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineVertex {
+    pub number: u64,
+    pub text: String,
+    pub file_path: String,
+}
+
+/// Fields pulled out of `std::fs::Metadata` at the time a vertex is
+/// constructed, so the vertex stays `Eq`/`Serialize` without having to
+/// carry the non-serializable `Metadata` type itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilesystemMetadata {
+    pub size_bytes: u64,
+    pub modified_unix_timestamp: Option<i64>,
+    pub created_unix_timestamp: Option<i64>,
+    pub is_readonly: bool,
+    #[cfg(unix)]
+    pub inode: u64,
+}
+
+impl FilesystemMetadata {
+    fn from_metadata(metadata: &Metadata) -> Self {
+        let to_unix_timestamp = |time: std::io::Result<std::time::SystemTime>| {
+            time.ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs() as i64)
+        };
+        FilesystemMetadata {
+            size_bytes: metadata.len(),
+            modified_unix_timestamp: to_unix_timestamp(metadata.modified()),
+            created_unix_timestamp: to_unix_timestamp(metadata.created()),
+            is_readonly: metadata.permissions().readonly(),
+            #[cfg(unix)]
+            inode: metadata.ino(),
+        }
+    }
+}
+
+fn resolve_metadata_property(
+    metadata: &Option<FilesystemMetadata>,
+    property_name: &str,
+) -> FieldValue {
+    let Some(metadata) = metadata.as_ref() else {
+        return FieldValue::Null;
+    };
+    match property_name {
+        "size_bytes" => FieldValue::Uint64(metadata.size_bytes),
+        "modified_unix_timestamp" => {
+            metadata.modified_unix_timestamp.map(FieldValue::Int64).unwrap_or(FieldValue::Null)
+        }
+        "created_unix_timestamp" => {
+            metadata.created_unix_timestamp.map(FieldValue::Int64).unwrap_or(FieldValue::Null)
+        }
+        "is_readonly" => FieldValue::Boolean(metadata.is_readonly),
+        #[cfg(unix)]
+        "inode" => FieldValue::Uint64(metadata.inode),
+        _ => unreachable!("unknown metadata property: {property_name}"),
+    }
+}
+
+/// Streams `path` through a BLAKE3 hasher in fixed-size chunks, instead of
+/// loading the whole file into memory, and returns its lowercase hex digest.
+fn compute_content_hash(path: &Path) -> Option<String> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = blake3::Hasher::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let bytes_read = file.read(&mut buffer).ok()?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..bytes_read]);
+    }
+    Some(hasher.finalize().to_hex().to_string())
+}
+
+/// Reads `file`'s full contents as UTF-8 text, returning `None` on a read
+/// error or if the bytes aren't valid UTF-8.
+fn file_content(root: &FilesystemRoot, file: &FileVertex) -> Option<String> {
+    let mut buf = PathBuf::new();
+    buf.extend([root.origin.as_str(), &file.path]);
+    fs::read_to_string(buf).ok()
+}
+
+/// Reports the dominant line terminator used in `content`: `"LF"` if every
+/// line ending is a bare `\n`, `"CRLF"` if every one is `\r\n`, `"Mixed"` if
+/// both appear, or `None` if the file has no line endings at all.
+fn detect_line_ending(content: &str) -> Option<&'static str> {
+    let mut saw_lf = false;
+    let mut saw_crlf = false;
+    let bytes = content.as_bytes();
+    for (index, &byte) in bytes.iter().enumerate() {
+        if byte != b'\n' {
+            continue;
+        }
+        if index > 0 && bytes[index - 1] == b'\r' {
+            saw_crlf = true;
+        } else {
+            saw_lf = true;
+        }
+    }
+
+    match (saw_lf, saw_crlf) {
+        (true, true) => Some("Mixed"),
+        (true, false) => Some("LF"),
+        (false, true) => Some("CRLF"),
+        (false, false) => None,
+    }
+}
+
+fn file_content_hash(root: &FilesystemRoot, file: &FileVertex) -> Option<String> {
+    let mut buf = PathBuf::new();
+    buf.extend([root.origin.as_str(), &file.path]);
+    compute_content_hash(&buf)
+}
+
+/// Walks the entire tree rooted at `root`, eagerly, to seed the
+/// content-hash index. Unlike `AllDescendantFilesIterator` this isn't meant
+/// to be lazy: it's only ever run once, the first time it's needed.
+fn walk_all_files(root: &FilesystemRoot) -> Vec<FileVertex> {
+    let root_dir =
+        DirectoryVertex { name: "<origin>".to_owned(), path: String::new(), metadata: None };
+    AllDescendantFilesIterator::new(root.clone(), &root_dir, None)
+        .filter_map(|vertex| match vertex {
+            FilesystemVertex::File(file) => Some(file),
+            FilesystemVertex::Directory(_)
+            | FilesystemVertex::Symlink(_)
+            | FilesystemVertex::Line(_) => None,
+        })
+        .collect()
+}
+
+fn ensure_content_hash_index(root: &FilesystemRoot, index: &ContentHashIndex) {
+    if index.borrow().is_some() {
+        return;
+    }
+    let mut map: HashMap<String, Vec<FileVertex>> = HashMap::new();
+    for file in walk_all_files(root) {
+        if let Some(hash) = file_content_hash(root, &file) {
+            map.entry(hash).or_default().push(file);
+        }
+    }
+    *index.borrow_mut() = Some(map);
+}
+
+fn same_contents_group(
+    root: &FilesystemRoot,
+    index: &ContentHashIndex,
+    file: &FileVertex,
+) -> Vec<FileVertex> {
+    let Some(hash) = file_content_hash(root, file) else {
+        return Vec::new();
+    };
+    ensure_content_hash_index(root, index);
+    let borrowed = index.borrow();
+    borrowed
+        .as_ref()
+        .and_then(|map| map.get(&hash))
+        .map(|files| files.iter().filter(|other| other.path != file.path).cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Resolves a symlink's target relative to its parent directory and
+/// `origin`, returning the `File` or `Directory` vertex it points at, or
+/// `None` if the link is dangling or points outside of `origin`.
+fn resolve_symlink_target(root: &FilesystemRoot, link: &SymlinkVertex) -> Option<FilesystemVertex> {
+    let mut link_full_path = PathBuf::new();
+    link_full_path.extend([root.origin.as_str(), &link.path]);
+    let parent = link_full_path.parent()?;
+    let target_path = parent.join(&link.target);
+
+    let canonical_target = fs::canonicalize(&target_path).ok()?;
+    let canonical_origin = fs::canonicalize(root.origin.as_str()).ok()?;
+    let relative_path =
+        canonical_target.strip_prefix(&canonical_origin).ok()?.to_str()?.to_owned();
+    let name = canonical_target.file_name()?.to_str()?.to_owned();
+    let metadata = fs::metadata(&canonical_target).ok()?;
+
+    if metadata.is_dir() {
+        Some(FilesystemVertex::Directory(DirectoryVertex {
+            name,
+            path: relative_path,
+            metadata: Some(FilesystemMetadata::from_metadata(&metadata)),
+        }))
+    } else if metadata.is_file() {
+        let extension = Path::new(&name).extension().map(|x| x.to_str().unwrap().to_owned());
+        Some(FilesystemVertex::File(FileVertex {
+            name,
+            extension,
+            path: relative_path,
+            metadata: Some(FilesystemMetadata::from_metadata(&metadata)),
+        }))
+    } else {
+        None
+    }
+}
+
+fn symlink_target_handler<'a>(
+    root: FilesystemRoot,
+    vertex: &FilesystemVertex,
+) -> VertexIterator<'a, FilesystemVertex> {
+    let symlink_vertex = match vertex {
+        FilesystemVertex::Symlink(link) => link,
+        _ => unreachable!(),
+    };
+    match resolve_symlink_target(&root, symlink_vertex) {
+        Some(target) => Box::from(iter::once(target)),
+        None => {
+            let empty_iterator: iter::Empty<FilesystemVertex> = iter::empty();
+            Box::from(empty_iterator)
+        }
+    }
 }
 
 fn directory_contains_file_handler<'a>(
-    origin: Rc<String>,
+    root: FilesystemRoot,
     vertex: &FilesystemVertex,
 ) -> VertexIterator<'a, FilesystemVertex> {
     let directory_vertex = match vertex {
         FilesystemVertex::Directory(dir) => dir,
         _ => unreachable!(),
     };
-    Box::from(DirectoryContainsFileIterator::new(origin, directory_vertex))
+    Box::from(DirectoryContainsFileIterator::new(root, directory_vertex))
 }
 
 fn directory_subdirectory_handler<'a>(
-    origin: Rc<String>,
+    root: FilesystemRoot,
     vertex: &FilesystemVertex,
 ) -> VertexIterator<'a, FilesystemVertex> {
     let directory_vertex = match vertex {
         FilesystemVertex::Directory(dir) => dir,
         _ => unreachable!(),
     };
-    Box::from(SubdirectoryIterator::new(origin, directory_vertex))
+    Box::from(SubdirectoryIterator::new(root, directory_vertex))
+}
+
+fn directory_contains_symlink_handler<'a>(
+    root: FilesystemRoot,
+    vertex: &FilesystemVertex,
+) -> VertexIterator<'a, FilesystemVertex> {
+    let directory_vertex = match vertex {
+        FilesystemVertex::Directory(dir) => dir,
+        _ => unreachable!(),
+    };
+    Box::from(DirectoryContainsSymlinkIterator::new(root, directory_vertex))
+}
+
+fn directory_all_descendant_files_handler<'a>(
+    root: FilesystemRoot,
+    vertex: &FilesystemVertex,
+    max_depth: Option<u64>,
+) -> VertexIterator<'a, FilesystemVertex> {
+    let directory_vertex = match vertex {
+        FilesystemVertex::Directory(dir) => dir,
+        _ => unreachable!(),
+    };
+    Box::from(AllDescendantFilesIterator::new(root, directory_vertex, max_depth))
+}
+
+fn directory_all_descendant_directories_handler<'a>(
+    root: FilesystemRoot,
+    vertex: &FilesystemVertex,
+    max_depth: Option<u64>,
+) -> VertexIterator<'a, FilesystemVertex> {
+    let directory_vertex = match vertex {
+        FilesystemVertex::Directory(dir) => dir,
+        _ => unreachable!(),
+    };
+    Box::from(AllDescendantDirectoriesIterator::new(root, directory_vertex, max_depth))
+}
+
+/// Parses the `max_depth` edge parameter shared by `out_Directory_AllDescendantFiles`
+/// and `out_Directory_AllDescendantDirectories`. `None` (the parameter
+/// omitted) means an unbounded traversal. `Some(0)` means "don't descend
+/// past the starting directory's immediate children" — i.e. only entries
+/// one level of nesting below the starting directory are returned.
+/// `Some(n)` for `n > 0` allows descending `n` additional levels beyond
+/// that, so entries up to `n + 1` levels below the starting directory are
+/// returned. Both descendant iterators apply this bound identically via
+/// [`should_descend`].
+fn max_depth_parameter(parameters: &EdgeParameters) -> Option<u64> {
+    match parameters.get("max_depth") {
+        Some(FieldValue::Int64(depth)) => Some(*depth as u64),
+        Some(FieldValue::Uint64(depth)) => Some(*depth),
+        _ => None,
+    }
+}
+
+/// Whether a directory discovered while listing a frame at `frame_depth`
+/// should itself be queued up for listing (letting the traversal descend
+/// one level further), per the `max_depth` bound described on
+/// [`max_depth_parameter`]. Shared by both descendant iterators so they
+/// apply the exact same depth semantics.
+fn should_descend(frame_depth: u64, max_depth: Option<u64>) -> bool {
+    max_depth.map_or(true, |max| frame_depth < max)
+}
+
+fn file_same_contents_handler<'a>(
+    root: FilesystemRoot,
+    index: ContentHashIndex,
+    vertex: &FilesystemVertex,
+) -> VertexIterator<'a, FilesystemVertex> {
+    let file_vertex = match vertex {
+        FilesystemVertex::File(file) => file,
+        _ => unreachable!(),
+    };
+    let group = same_contents_group(&root, &index, file_vertex);
+    Box::from(group.into_iter().map(FilesystemVertex::File))
+}
+
+fn file_contains_line_handler<'a>(
+    root: FilesystemRoot,
+    vertex: &FilesystemVertex,
+) -> VertexIterator<'a, FilesystemVertex> {
+    let file_vertex = match vertex {
+        FilesystemVertex::File(file) => file,
+        _ => unreachable!(),
+    };
+    Box::from(ContainsLineIterator::new(&root, file_vertex))
+}
+
+const DIRECTORY_PROPERTIES: &[&str] = &[
+    "name",
+    "path",
+    "__typename",
+    "size_bytes",
+    "modified_unix_timestamp",
+    "created_unix_timestamp",
+    "is_readonly",
+    #[cfg(unix)]
+    "inode",
+];
+const FILE_PROPERTIES: &[&str] = &[
+    "name",
+    "path",
+    "extension",
+    "__typename",
+    "size_bytes",
+    "modified_unix_timestamp",
+    "created_unix_timestamp",
+    "is_readonly",
+    #[cfg(unix)]
+    "inode",
+    "content_hash",
+    "content",
+    "line_ending",
+];
+const SYMLINK_PROPERTIES: &[&str] = &["name", "path", "target", "__typename"];
+const LINE_PROPERTIES: &[&str] = &["number", "text", "__typename"];
+const VERTEX_TYPES: &[&str] = &["Directory", "File", "Symlink", "Line"];
+
+const DIRECTORY_EDGES: &[&str] = &[
+    "out_Directory_ContainsFile",
+    "out_Directory_Subdirectory",
+    "out_Directory_ContainsSymlink",
+    "out_Directory_AllDescendantFiles",
+    "out_Directory_AllDescendantDirectories",
+];
+const FILE_EDGES: &[&str] = &["out_File_SameContents", "out_File_ContainsLine"];
+const SYMLINK_EDGES: &[&str] = &["out_Symlink_Target"];
+
+/// Computes the Levenshtein edit distance between `a` and `b` with the
+/// standard two-row dynamic-programming table (cost 1 for insert, delete,
+/// and substitute).
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current_row = vec![0usize; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current_row[0] = i + 1;
+        for (j, &b_char) in b_chars.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j + 1] + 1)
+                .min(current_row[j] + 1)
+                .min(previous_row[j] + cost);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b_chars.len()]
+}
+
+/// Finds the candidate closest to `name` by edit distance, only suggesting
+/// it when the distance is small relative to `name`'s length (at most 3, or
+/// a third of `name`'s length, whichever is larger).
+fn suggest_closest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = std::cmp::max(3, name.chars().count() / 3);
+    candidates
+        .iter()
+        .map(|&candidate| (candidate, levenshtein_distance(name, candidate)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// The concrete type name of a vertex, as used throughout `resolve_property`
+/// and `resolve_neighbors`'s `type_name` dispatch.
+fn vertex_type_name(vertex: &FilesystemVertex) -> &'static str {
+    match vertex {
+        FilesystemVertex::Directory(_) => "Directory",
+        FilesystemVertex::File(_) => "File",
+        FilesystemVertex::Symlink(_) => "Symlink",
+        FilesystemVertex::Line(_) => "Line",
+    }
+}
+
+fn panic_unknown_type(type_name: &str) -> ! {
+    match suggest_closest(type_name, VERTEX_TYPES) {
+        Some(suggestion) => panic!("unknown type `{type_name}`; did you mean `{suggestion}`?"),
+        None => panic!("unknown type `{type_name}`"),
+    }
+}
+
+fn panic_unknown_property(type_name: &str, property_name: &str, candidates: &[&str]) -> ! {
+    match suggest_closest(property_name, candidates) {
+        Some(suggestion) => panic!(
+            "unknown property `{property_name}` on `{type_name}`; did you mean `{suggestion}`?"
+        ),
+        None => panic!("unknown property `{property_name}` on `{type_name}`"),
+    }
+}
+
+fn panic_unknown_edge(type_name: &str, edge_name: &str, candidates: &[&str]) -> ! {
+    match suggest_closest(edge_name, candidates) {
+        Some(suggestion) => {
+            panic!("unknown edge `{edge_name}` on `{type_name}`; did you mean `{suggestion}`?")
+        }
+        None => panic!("unknown edge `{edge_name}` on `{type_name}`"),
+    }
 }
 
 #[allow(unused_variables)]
@@ -250,7 +1090,12 @@ impl<'a> Adapter<'a> for FilesystemInterpreter {
     ) -> VertexIterator<'a, Self::Vertex> {
         assert!(edge_name.as_ref() == "OriginDirectory");
         assert!(parameters.is_empty());
-        let vertex = DirectoryVertex { name: "<origin>".to_owned(), path: "".to_owned() };
+        let metadata =
+            fs::metadata(self.root.origin.as_str())
+                .ok()
+                .map(|m| FilesystemMetadata::from_metadata(&m));
+        let vertex =
+            DirectoryVertex { name: "<origin>".to_owned(), path: "".to_owned(), metadata };
         Box::new(OriginIterator::new(vertex))
     }
 
@@ -290,7 +1135,33 @@ impl<'a> Adapter<'a> for FilesystemInterpreter {
                             Some(_) => (context, "Directory".into()),
                         }
                     })),
-                    _ => todo!(),
+                    "size_bytes" | "modified_unix_timestamp" | "created_unix_timestamp"
+                    | "is_readonly" => {
+                        let property_name = property_name.clone();
+                        Box::new(contexts.map(move |context| {
+                            match context.active_vertex::<Self::Vertex>() {
+                                None => (context, FieldValue::Null),
+                                Some(FilesystemVertex::Directory(ref x)) => {
+                                    let value =
+                                        resolve_metadata_property(&x.metadata, &property_name);
+                                    (context, value)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }))
+                    }
+                    #[cfg(unix)]
+                    "inode" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::Directory(ref x)) => {
+                                let value = resolve_metadata_property(&x.metadata, "inode");
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    _ => panic_unknown_property("Directory", property_name, DIRECTORY_PROPERTIES),
                 }
             }
             "File" => {
@@ -332,10 +1203,155 @@ impl<'a> Adapter<'a> for FilesystemInterpreter {
                             Some(_) => (context, "File".into()),
                         }
                     })),
-                    _ => todo!(),
+                    "size_bytes" | "modified_unix_timestamp" | "created_unix_timestamp"
+                    | "is_readonly" => {
+                        let property_name = property_name.clone();
+                        Box::new(contexts.map(move |context| {
+                            match context.active_vertex::<Self::Vertex>() {
+                                None => (context, FieldValue::Null),
+                                Some(FilesystemVertex::File(ref x)) => {
+                                    let value =
+                                        resolve_metadata_property(&x.metadata, &property_name);
+                                    (context, value)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }))
+                    }
+                    #[cfg(unix)]
+                    "inode" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::File(ref x)) => {
+                                let value = resolve_metadata_property(&x.metadata, "inode");
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    "content_hash" => {
+                        let root = self.root.clone();
+                        Box::new(contexts.map(move |context| {
+                            match context.active_vertex::<Self::Vertex>() {
+                                None => (context, FieldValue::Null),
+                                Some(FilesystemVertex::File(ref x)) => {
+                                    let value = file_content_hash(&root, x)
+                                        .map(Into::into)
+                                        .unwrap_or(FieldValue::Null);
+                                    (context, value)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }))
+                    }
+                    "content" => {
+                        let root = self.root.clone();
+                        Box::new(contexts.map(move |context| {
+                            match context.active_vertex::<Self::Vertex>() {
+                                None => (context, FieldValue::Null),
+                                Some(FilesystemVertex::File(ref x)) => {
+                                    let value = file_content(&root, x)
+                                        .map(Into::into)
+                                        .unwrap_or(FieldValue::Null);
+                                    (context, value)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }))
+                    }
+                    "line_ending" => {
+                        let root = self.root.clone();
+                        Box::new(contexts.map(move |context| {
+                            match context.active_vertex::<Self::Vertex>() {
+                                None => (context, FieldValue::Null),
+                                Some(FilesystemVertex::File(ref x)) => {
+                                    let value = file_content(&root, x)
+                                        .as_deref()
+                                        .and_then(detect_line_ending)
+                                        .map(Into::into)
+                                        .unwrap_or(FieldValue::Null);
+                                    (context, value)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }))
+                    }
+                    _ => panic_unknown_property("File", property_name, FILE_PROPERTIES),
                 }
             }
-            _ => todo!(),
+            "Symlink" => {
+                match property_name.as_ref() {
+                    "name" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::Symlink(ref x)) => {
+                                let value = FieldValue::String(x.name.clone().into());
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    "path" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::Symlink(ref x)) => {
+                                let value = FieldValue::String(x.path.clone().into());
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    "target" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::Symlink(ref x)) => {
+                                let value = FieldValue::String(x.target.clone().into());
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    "__typename" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(_) => (context, "Symlink".into()),
+                        }
+                    })),
+                    _ => panic_unknown_property("Symlink", property_name, SYMLINK_PROPERTIES),
+                }
+            }
+            "Line" => {
+                match property_name.as_ref() {
+                    "number" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::Line(ref x)) => {
+                                let value = FieldValue::Uint64(x.number);
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    "text" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(FilesystemVertex::Line(ref x)) => {
+                                let value = FieldValue::String(x.text.clone().into());
+                                (context, value)
+                            }
+                            _ => unreachable!(),
+                        }
+                    })),
+                    "__typename" => Box::new(contexts.map(|context| {
+                        match context.active_vertex::<Self::Vertex>() {
+                            None => (context, FieldValue::Null),
+                            Some(_) => (context, "Line".into()),
+                        }
+                    })),
+                    _ => panic_unknown_property("Line", property_name, LINE_PROPERTIES),
+                }
+            }
+            _ => panic_unknown_type(type_name),
         }
     }
 
@@ -350,7 +1366,7 @@ impl<'a> Adapter<'a> for FilesystemInterpreter {
         match (type_name.as_ref(), edge_name.as_ref()) {
             ("Directory", "out_Directory_ContainsFile") => {
                 let iterator = EdgeResolverIterator::new(
-                    self.origin.clone(),
+                    self.root.clone(),
                     contexts,
                     directory_contains_file_handler,
                 );
@@ -358,13 +1374,70 @@ impl<'a> Adapter<'a> for FilesystemInterpreter {
             }
             ("Directory", "out_Directory_Subdirectory") => {
                 let iterator = EdgeResolverIterator::new(
-                    self.origin.clone(),
+                    self.root.clone(),
                     contexts,
                     directory_subdirectory_handler,
                 );
                 Box::from(iterator)
             }
-            _ => unimplemented!(),
+            ("Directory", "out_Directory_ContainsSymlink") => {
+                let iterator = EdgeResolverIterator::new(
+                    self.root.clone(),
+                    contexts,
+                    directory_contains_symlink_handler,
+                );
+                Box::from(iterator)
+            }
+            ("Directory", "out_Directory_AllDescendantFiles") => {
+                let iterator = ParameterizedEdgeResolverIterator::new(
+                    self.root.clone(),
+                    contexts,
+                    directory_all_descendant_files_handler,
+                    max_depth_parameter(parameters),
+                );
+                Box::from(iterator)
+            }
+            ("Directory", "out_Directory_AllDescendantDirectories") => {
+                let iterator = ParameterizedEdgeResolverIterator::new(
+                    self.root.clone(),
+                    contexts,
+                    directory_all_descendant_directories_handler,
+                    max_depth_parameter(parameters),
+                );
+                Box::from(iterator)
+            }
+            ("File", "out_File_SameContents") => {
+                let iterator = CachedEdgeResolverIterator::new(
+                    self.root.clone(),
+                    self.content_hash_index.clone(),
+                    contexts,
+                    file_same_contents_handler,
+                );
+                Box::from(iterator)
+            }
+            ("File", "out_File_ContainsLine") => {
+                let iterator = EdgeResolverIterator::new(
+                    self.root.clone(),
+                    contexts,
+                    file_contains_line_handler,
+                );
+                Box::from(iterator)
+            }
+            ("Symlink", "out_Symlink_Target") => {
+                let iterator =
+                    EdgeResolverIterator::new(self.root.clone(), contexts, symlink_target_handler);
+                Box::from(iterator)
+            }
+            (type_name, edge_name) => {
+                let candidates = match type_name {
+                    "Directory" => DIRECTORY_EDGES,
+                    "File" => FILE_EDGES,
+                    "Symlink" => SYMLINK_EDGES,
+                    "Line" => &[],
+                    _ => panic_unknown_type(type_name),
+                };
+                panic_unknown_edge(type_name, edge_name, candidates)
+            }
         }
     }
 
@@ -375,6 +1448,13 @@ impl<'a> Adapter<'a> for FilesystemInterpreter {
         coerce_to_type: &Arc<str>,
         resolve_info: &ResolveInfo,
     ) -> ContextOutcomeIterator<'a, V, bool> {
-        todo!()
+        let coerce_to_type = coerce_to_type.clone();
+        Box::new(contexts.map(move |context| {
+            let can_coerce = match context.active_vertex::<Self::Vertex>() {
+                None => false,
+                Some(vertex) => vertex_type_name(vertex) == coerce_to_type.as_ref(),
+            };
+            (context, can_coerce)
+        }))
     }
 }